@@ -0,0 +1,168 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub(crate) enum StorageConfig {
+    S3(S3StorageConfig),
+    Gcs(GcsStorageConfig),
+    Azure(AzureStorageConfig),
+}
+
+#[derive(Deserialize)]
+pub(crate) struct GcsStorageConfig {
+    pub(crate) bucket: String,
+    // Path to a service-account key file. When unset the default GCP credential
+    // chain (ADC, workload identity, metadata server) is used.
+    pub(crate) service_account_path: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AzureStorageConfig {
+    // Blob storage account name.
+    pub(crate) account: String,
+    // Container the objects live in.
+    pub(crate) container: String,
+    // Shared-key access key. When unset the default Azure credential chain is
+    // used.
+    pub(crate) access_key: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct S3StorageConfig {
+    pub(crate) bucket: String,
+    // When set, points the client at an S3-compatible endpoint (MinIO, Garage,
+    // Ceph RGW, ...) instead of the real AWS S3 service.
+    pub(crate) endpoint_url: Option<String>,
+    // Region to pin the client to. Required by most S3-compatible stores, which
+    // don't do the region resolution the AWS SDK expects from the environment.
+    pub(crate) region: Option<String>,
+    // Static credentials. Both must be set together to take effect, and they are
+    // only used while `credentials` is left at `Env`; otherwise the client falls
+    // back to the ambient environment as before.
+    pub(crate) aws_access_key_id: Option<String>,
+    pub(crate) aws_secret_access_key: Option<String>,
+    // Use path-style addressing (`host/bucket/key`) instead of virtual-hosted
+    // addressing (`bucket.host/key`). S3-compatible stores generally need this.
+    #[serde(default)]
+    pub(crate) force_path_style: bool,
+    // Selects how credentials are resolved. Defaults to the ambient environment
+    // so existing deployments keep working without config changes.
+    #[serde(default)]
+    pub(crate) credentials: S3CredentialsConfig,
+    // Files at or above this size are uploaded by `put_file` with multipart
+    // instead of a single PUT; `put_bytes` always uses a single PUT. Defaults to
+    // 32 MiB.
+    #[serde(default = "default_multipart_threshold_bytes")]
+    pub(crate) multipart_threshold_bytes: usize,
+    // Size of each multipart part. S3 requires parts (except the last) to be at
+    // least 5 MiB; defaults to 8 MiB.
+    #[serde(default = "default_upload_part_size_bytes")]
+    pub(crate) upload_part_size_bytes: usize,
+    // Upper bound on how many parts are uploaded concurrently.
+    #[serde(default = "default_max_concurrent_upload_parts")]
+    pub(crate) max_concurrent_upload_parts: usize,
+    // Retry policy applied to transient object-store failures.
+    #[serde(default)]
+    pub(crate) retry: StorageRetryConfig,
+}
+
+#[derive(Deserialize, Clone)]
+pub(crate) struct StorageRetryConfig {
+    // Number of retries attempted after the initial try before giving up.
+    #[serde(default = "default_max_retries")]
+    pub(crate) max_retries: u32,
+    // Backoff ceiling for the first retry; doubles each subsequent attempt. The
+    // actual delay is drawn uniformly from zero up to the ceiling (full jitter).
+    #[serde(default = "default_base_delay_ms")]
+    pub(crate) base_delay_ms: u64,
+    // Upper bound the exponential backoff is capped at.
+    #[serde(default = "default_max_delay_ms")]
+    pub(crate) max_delay_ms: u64,
+}
+
+impl Default for StorageRetryConfig {
+    fn default() -> Self {
+        StorageRetryConfig {
+            max_retries: default_max_retries(),
+            base_delay_ms: default_base_delay_ms(),
+            max_delay_ms: default_max_delay_ms(),
+        }
+    }
+}
+
+fn default_max_retries() -> u32 {
+    5
+}
+
+fn default_base_delay_ms() -> u64 {
+    50
+}
+
+fn default_max_delay_ms() -> u64 {
+    5_000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_defaults() {
+        let retry = StorageRetryConfig::default();
+        assert_eq!(retry.max_retries, 5);
+        assert_eq!(retry.base_delay_ms, 50);
+        assert_eq!(retry.max_delay_ms, 5_000);
+    }
+
+    #[test]
+    fn test_credentials_default_is_env() {
+        // Existing deployments must keep resolving credentials from the ambient
+        // environment when the selector is omitted.
+        assert!(matches!(
+            S3CredentialsConfig::default(),
+            S3CredentialsConfig::Env
+        ));
+    }
+
+    #[test]
+    fn test_multipart_defaults() {
+        assert_eq!(default_multipart_threshold_bytes(), 32 * 1024 * 1024);
+        assert_eq!(default_upload_part_size_bytes(), 8 * 1024 * 1024);
+        assert_eq!(default_max_concurrent_upload_parts(), 8);
+    }
+}
+
+fn default_multipart_threshold_bytes() -> usize {
+    32 * 1024 * 1024
+}
+
+fn default_upload_part_size_bytes() -> usize {
+    8 * 1024 * 1024
+}
+
+fn default_max_concurrent_upload_parts() -> usize {
+    8
+}
+
+#[derive(Deserialize, Clone)]
+pub(crate) enum S3CredentialsConfig {
+    // Explicit long-lived credentials supplied in config.
+    Static {
+        aws_access_key_id: String,
+        aws_secret_access_key: String,
+    },
+    // Whatever the default environment chain resolves (env vars, shared config
+    // files, container/instance metadata) — the historical behavior.
+    Env,
+    // Exchange a web identity token for credentials via STS. Reads
+    // `AWS_ROLE_ARN`/`AWS_WEB_IDENTITY_TOKEN_FILE`; used for IAM roles for
+    // service accounts (IRSA) when running as a Kubernetes pod.
+    WebIdentityToken,
+    // EC2 instance metadata (IMDS) credentials.
+    InstanceMetadata,
+}
+
+impl Default for S3CredentialsConfig {
+    fn default() -> Self {
+        S3CredentialsConfig::Env
+    }
+}
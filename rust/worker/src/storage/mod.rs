@@ -0,0 +1,70 @@
+pub(crate) mod azure;
+pub(crate) mod config;
+pub(crate) mod gcs;
+pub(crate) mod s3;
+
+use self::azure::AzureStorage;
+use self::config::StorageConfig;
+use self::gcs::GcsStorage;
+use self::s3::S3Storage;
+use crate::config::Configurable;
+use crate::errors::ChromaError;
+use tokio::io::AsyncBufRead;
+
+// Backend-agnostic key-value store. Each variant maps a flat key space onto a
+// concrete object store; callers use the enum methods below and stay oblivious
+// to which backend they're talking to.
+#[derive(Clone)]
+pub(crate) enum Storage {
+    S3(s3::S3Storage),
+    Gcs(gcs::GcsStorage),
+    Azure(azure::AzureStorage),
+}
+
+impl Storage {
+    pub(crate) async fn get(
+        &self,
+        key: &str,
+    ) -> Result<Box<dyn AsyncBufRead + Unpin + Send>, String> {
+        match self {
+            Storage::S3(s3) => s3.get(key).await,
+            Storage::Gcs(gcs) => gcs.get(key).await,
+            Storage::Azure(azure) => azure.get(key).await,
+        }
+    }
+
+    pub(crate) async fn put_bytes(&self, key: &str, bytes: Vec<u8>) -> Result<(), String> {
+        match self {
+            Storage::S3(s3) => s3.put_bytes(key, bytes).await,
+            Storage::Gcs(gcs) => gcs.put_bytes(key, bytes).await,
+            Storage::Azure(azure) => azure.put_bytes(key, bytes).await,
+        }
+    }
+
+    pub(crate) async fn put_file(&self, key: &str, path: &str) -> Result<(), String> {
+        match self {
+            Storage::S3(s3) => s3.put_file(key, path).await,
+            Storage::Gcs(gcs) => gcs.put_file(key, path).await,
+            Storage::Azure(azure) => azure.put_file(key, path).await,
+        }
+    }
+}
+
+pub(crate) async fn from_config(
+    config: &StorageConfig,
+) -> Result<Box<Storage>, Box<dyn ChromaError>> {
+    match &config {
+        StorageConfig::S3(_) => {
+            let s3_storage = S3Storage::try_from_config(config).await?;
+            return Ok(Box::new(Storage::S3(s3_storage)));
+        }
+        StorageConfig::Gcs(_) => {
+            let gcs_storage = GcsStorage::try_from_config(config).await?;
+            return Ok(Box::new(Storage::Gcs(gcs_storage)));
+        }
+        StorageConfig::Azure(_) => {
+            let azure_storage = AzureStorage::try_from_config(config).await?;
+            return Ok(Box::new(Storage::Azure(azure_storage)));
+        }
+    }
+}
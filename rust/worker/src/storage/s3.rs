@@ -8,7 +8,8 @@
 // Once we move to our own implementation of hnswlib we can support
 // streaming from s3.
 
-use super::{config::StorageConfig, Storage};
+use super::config::{S3CredentialsConfig, StorageConfig, StorageRetryConfig};
+use super::Storage;
 use crate::config::Configurable;
 use crate::errors::ChromaError;
 use async_trait::async_trait;
@@ -17,8 +18,11 @@ use aws_sdk_s3::error::SdkError;
 use aws_sdk_s3::operation::create_bucket::CreateBucketError;
 use aws_smithy_types::byte_stream::ByteStream;
 use bytes::Bytes;
+use futures::stream::{BoxStream, StreamExt, TryStreamExt};
+use rand::Rng;
 use std::clone::Clone;
 use std::io::Write;
+use std::ops::Range;
 use thiserror::Error;
 use tokio::io::AsyncBufRead;
 
@@ -26,16 +30,66 @@ use tokio::io::AsyncBufRead;
 pub(crate) struct S3Storage {
     bucket: String,
     client: aws_sdk_s3::Client,
+    multipart_threshold_bytes: usize,
+    upload_part_size_bytes: usize,
+    max_concurrent_upload_parts: usize,
+    retry: StorageRetryConfig,
 }
 
 impl S3Storage {
-    fn new(bucket: &str, client: aws_sdk_s3::Client) -> S3Storage {
+    fn new(
+        bucket: &str,
+        client: aws_sdk_s3::Client,
+        multipart_threshold_bytes: usize,
+        upload_part_size_bytes: usize,
+        max_concurrent_upload_parts: usize,
+        retry: StorageRetryConfig,
+    ) -> S3Storage {
         return S3Storage {
             bucket: bucket.to_string(),
             client: client,
+            multipart_threshold_bytes,
+            upload_part_size_bytes,
+            max_concurrent_upload_parts,
+            retry,
         };
     }
 
+    // Runs `op` under the configured retry policy. Errors classified as
+    // transient (5xx, throttling/`SlowDown`, timeouts, dispatch/connection
+    // failures) are retried with capped exponential backoff plus random jitter;
+    // terminal errors (4xx such as `NoSuchKey`) are surfaced immediately.
+    //
+    // The typed `SdkError` is preserved on the terminal error path so callers
+    // (e.g. conditional writes) can classify it from structured metadata rather
+    // than a stringified summary.
+    async fn with_retry<F, Fut, T, E>(&self, op: F) -> Result<T, SdkError<E>>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T, SdkError<E>>>,
+    {
+        let mut attempt: u32 = 0;
+        loop {
+            match op().await {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    if attempt >= self.retry.max_retries || !is_retryable(&e) {
+                        return Err(e);
+                    }
+                    let backoff = backoff_ceiling(
+                        self.retry.base_delay_ms,
+                        self.retry.max_delay_ms,
+                        attempt,
+                    );
+                    // Full jitter: sleep a uniformly random duration in [0, backoff].
+                    let jittered = rand::thread_rng().gen_range(0..=backoff);
+                    tokio::time::sleep(std::time::Duration::from_millis(jittered)).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
     async fn create_bucket(&self) -> Result<(), String> {
         // Creates a public bucket with default settings in the region.
         // This should only be used for testing and in production
@@ -48,26 +102,24 @@ impl S3Storage {
             .await;
         match res {
             Ok(_) => {
-                println!("created bucket {}", self.bucket);
+                tracing::debug!("created bucket {}", self.bucket);
                 return Ok(());
             }
             Err(e) => match e {
                 SdkError::ServiceError(err) => match err.into_err() {
                     CreateBucketError::BucketAlreadyExists(msg) => {
-                        println!("bucket already exists: {}", msg);
+                        tracing::debug!("bucket already exists: {}", msg);
                         return Ok(());
                     }
                     CreateBucketError::BucketAlreadyOwnedByYou(msg) => {
-                        println!("bucket already owned by you: {}", msg);
+                        tracing::debug!("bucket already owned by you: {}", msg);
                         return Ok(());
                     }
                     e => {
-                        println!("error: {}", e.to_string());
                         return Err::<(), String>(e.to_string());
                     }
                 },
                 _ => {
-                    println!("error: {}", e);
                     return Err::<(), String>(e.to_string());
                 }
             },
@@ -79,21 +131,64 @@ impl S3Storage {
         key: &str,
     ) -> Result<Box<dyn AsyncBufRead + Unpin + Send>, String> {
         let res = self
-            .client
-            .get_object()
-            .bucket(self.bucket.clone())
-            .key(key)
-            .send()
-            .await;
-        match res {
-            Ok(res) => {
-                return Ok(Box::new(res.body.into_async_read()));
-            }
-            Err(e) => {
-                println!("error: {}", e);
-                return Err::<_, String>(e.to_string());
-            }
-        }
+            .with_retry(|| {
+                self.client
+                    .get_object()
+                    .bucket(self.bucket.clone())
+                    .key(key)
+                    .send()
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(Box::new(res.body.into_async_read()))
+    }
+
+    // Fetches a byte range of an object, setting the HTTP `Range` header. The
+    // range is half-open `[start, end)` to match `std::ops::Range`; it is sent
+    // to S3 as the inclusive `bytes=start-{end-1}`. Lets callers read only the
+    // blocks of a blockfile or HNSW segment they actually need.
+    pub(crate) async fn get_range(
+        &self,
+        key: &str,
+        range: Range<u64>,
+    ) -> Result<Bytes, String> {
+        let header = range_header(&range)?;
+        let res = self
+            .with_retry(|| {
+                self.client
+                    .get_object()
+                    .bucket(self.bucket.clone())
+                    .key(key)
+                    .range(header.clone())
+                    .send()
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+        res.body
+            .collect()
+            .await
+            .map(|data| data.into_bytes())
+            .map_err(|e| e.to_string())
+    }
+
+    // Streams an object as a sequence of chunks instead of materializing the
+    // whole body on disk. Lets callers read multi-gigabyte segments without
+    // buffering them entirely.
+    pub(crate) async fn get_stream(
+        &self,
+        key: &str,
+    ) -> Result<BoxStream<'static, Result<Bytes, String>>, String> {
+        let res = self
+            .with_retry(|| {
+                self.client
+                    .get_object()
+                    .bucket(self.bucket.clone())
+                    .key(key)
+                    .send()
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(res.body.map_err(|e| e.to_string()).boxed())
     }
 
     pub(crate) async fn put_bytes(&self, key: &str, bytes: Vec<u8>) -> Result<(), String> {
@@ -101,7 +196,97 @@ impl S3Storage {
         self.put_bytestream(key, bytestream).await
     }
 
+    // Like `put_bytes`, but guarded by a write precondition. With
+    // `PutPrecondition::IfNotExists` the write only succeeds if the object is
+    // absent (`If-None-Match: *`); with `IfMatch` it only succeeds if the
+    // current object's ETag matches. A failed precondition is reported as a
+    // distinct error variant so callers can make segment registration idempotent.
+    pub(crate) async fn put_bytes_conditional(
+        &self,
+        key: &str,
+        bytes: Vec<u8>,
+        precondition: PutPrecondition,
+    ) -> Result<(), StoragePutError> {
+        self.put_object_conditional(key, ByteStream::from(bytes), precondition)
+            .await
+    }
+
+    pub(crate) async fn put_file_conditional(
+        &self,
+        key: &str,
+        path: &str,
+        precondition: PutPrecondition,
+    ) -> Result<(), StoragePutError> {
+        let bytestream = ByteStream::from_path(path)
+            .await
+            .map_err(|e| StoragePutError::Other(e.to_string()))?;
+        self.put_object_conditional(key, bytestream, precondition)
+            .await
+    }
+
+    async fn put_object_conditional(
+        &self,
+        key: &str,
+        bytestream: ByteStream,
+        precondition: PutPrecondition,
+    ) -> Result<(), StoragePutError> {
+        // Buffer so the body can be re-sent on retry.
+        let bytes = bytestream
+            .collect()
+            .await
+            .map_err(|e| StoragePutError::Other(e.to_string()))?
+            .into_bytes();
+        let res = self
+            .with_retry(|| {
+                let mut req = self
+                    .client
+                    .put_object()
+                    .bucket(self.bucket.clone())
+                    .key(key)
+                    .body(ByteStream::from(bytes.clone()));
+                match &precondition {
+                    PutPrecondition::None => {}
+                    PutPrecondition::IfNotExists => req = req.if_none_match("*"),
+                    PutPrecondition::IfMatch(etag) => req = req.if_match(etag.clone()),
+                }
+                req.send()
+            })
+            .await;
+        match res {
+            Ok(_) => Ok(()),
+            Err(e) => Err(put_error_from_sdk(e, &precondition)),
+        }
+    }
+
+    // Fetches object metadata via `head_object` without downloading the body.
+    pub(crate) async fn head(&self, key: &str) -> Result<ObjectMeta, String> {
+        let res = self
+            .with_retry(|| {
+                self.client
+                    .head_object()
+                    .bucket(self.bucket.clone())
+                    .key(key)
+                    .send()
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(ObjectMeta {
+            size: res.content_length().unwrap_or(0),
+            etag: res.e_tag().map(|s| s.to_string()),
+            last_modified: res.last_modified().map(|t| t.to_string()),
+        })
+    }
+
     pub(crate) async fn put_file(&self, key: &str, path: &str) -> Result<(), String> {
+        let file_size = tokio::fs::metadata(path)
+            .await
+            .map_err(|e| e.to_string())?
+            .len() as usize;
+
+        if file_size >= self.multipart_threshold_bytes {
+            return self.put_file_multipart(key, path, file_size).await;
+        }
+
         let bytestream = ByteStream::from_path(path).await;
         match bytestream {
             Ok(bytestream) => {
@@ -113,24 +298,285 @@ impl S3Storage {
         }
     }
 
-    async fn put_bytestream(&self, key: &str, bytestream: ByteStream) -> Result<(), String> {
-        let res = self
-            .client
-            .put_object()
-            .bucket(self.bucket.clone())
-            .key(key)
-            .body(bytestream)
-            .send()
+    // Uploads a file as a multipart upload: parts are read from the file at
+    // fixed offsets and uploaded with bounded concurrency, ETags are collected
+    // in part order, and the upload is completed. Any part failure aborts the
+    // upload so S3 doesn't retain the orphaned parts.
+    async fn put_file_multipart(
+        &self,
+        key: &str,
+        path: &str,
+        file_size: usize,
+    ) -> Result<(), String> {
+        let create = self
+            .with_retry(|| {
+                self.client
+                    .create_multipart_upload()
+                    .bucket(self.bucket.clone())
+                    .key(key)
+                    .send()
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+        let upload_id = match create.upload_id {
+            Some(upload_id) => upload_id,
+            None => return Err("missing upload id from create_multipart_upload".to_string()),
+        };
+
+        let result = self
+            .upload_parts(key, path, file_size, &upload_id)
             .await;
-        match res {
-            Ok(_) => {
-                println!("put object {} to bucket {}", key, self.bucket);
-                return Ok(());
+        match result {
+            Ok(completed_parts) => {
+                let completed = aws_sdk_s3::types::CompletedMultipartUpload::builder()
+                    .set_parts(Some(completed_parts))
+                    .build();
+                self.with_retry(|| {
+                    self.client
+                        .complete_multipart_upload()
+                        .bucket(self.bucket.clone())
+                        .key(key)
+                        .upload_id(&upload_id)
+                        .multipart_upload(completed.clone())
+                        .send()
+                })
+                .await
+                .map_err(|e| e.to_string())?;
+                tracing::debug!("put object {} to bucket {} (multipart)", key, self.bucket);
+                Ok(())
             }
             Err(e) => {
-                println!("error: {}", e);
-                return Err::<(), String>(e.to_string());
+                // Best-effort abort; surface the original error regardless.
+                let _ = self
+                    .with_retry(|| {
+                        self.client
+                            .abort_multipart_upload()
+                            .bucket(self.bucket.clone())
+                            .key(key)
+                            .upload_id(&upload_id)
+                            .send()
+                    })
+                    .await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn upload_parts(
+        &self,
+        key: &str,
+        path: &str,
+        file_size: usize,
+        upload_id: &str,
+    ) -> Result<Vec<aws_sdk_s3::types::CompletedPart>, String> {
+        let plan = part_plan(file_size, self.upload_part_size_bytes);
+
+        let uploads = futures::stream::iter(plan).map(|part| {
+            let Part {
+                part_number,
+                offset,
+                length,
+            } = part;
+            async move {
+                // Buffer the part once so it can be re-sent on retry (`ByteStream`
+                // isn't cloneable). Parts are bounded by `upload_part_size_bytes`.
+                let bytes = ByteStream::read_from()
+                    .path(path)
+                    .offset(offset as u64)
+                    .length(aws_smithy_types::byte_stream::Length::Exact(length as u64))
+                    .build()
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .collect()
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .into_bytes();
+                let part = self
+                    .with_retry(|| {
+                        self.client
+                            .upload_part()
+                            .bucket(self.bucket.clone())
+                            .key(key)
+                            .upload_id(upload_id)
+                            .part_number(part_number)
+                            .body(ByteStream::from(bytes.clone()))
+                            .send()
+                    })
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Ok::<_, String>(
+                    aws_sdk_s3::types::CompletedPart::builder()
+                        .part_number(part_number)
+                        .set_e_tag(part.e_tag)
+                        .build(),
+                )
             }
+        });
+
+        let mut completed_parts = uploads
+            .buffer_unordered(self.max_concurrent_upload_parts)
+            .try_collect::<Vec<_>>()
+            .await?;
+        // `buffer_unordered` may yield parts out of order; S3 wants them sorted.
+        completed_parts.sort_by_key(|part| part.part_number());
+        Ok(completed_parts)
+    }
+
+    async fn put_bytestream(&self, key: &str, bytestream: ByteStream) -> Result<(), String> {
+        // `ByteStream` isn't cloneable, so retries re-read the buffered body.
+        let bytes = bytestream
+            .collect()
+            .await
+            .map_err(|e| e.to_string())?
+            .into_bytes();
+        self.with_retry(|| {
+            self.client
+                .put_object()
+                .bucket(self.bucket.clone())
+                .key(key)
+                .body(ByteStream::from(bytes.clone()))
+                .send()
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+        tracing::debug!("put object {} to bucket {}", key, self.bucket);
+        Ok(())
+    }
+}
+
+// Classifies an SDK error as transient (worth retrying) or terminal. Timeouts
+// and dispatch failures (dropped/reset connections) are always transient; for
+// service responses we retry 5xx and the well-known throttling codes and treat
+// everything else (4xx like `NoSuchKey`) as terminal.
+fn is_retryable<E>(err: &SdkError<E>) -> bool {
+    match err {
+        SdkError::TimeoutError(_) | SdkError::DispatchFailure(_) => true,
+        SdkError::ResponseError(e) => status_is_retryable(e.raw().status().as_u16()),
+        SdkError::ServiceError(e) => status_is_retryable(e.raw().status().as_u16()),
+        _ => false,
+    }
+}
+
+// HTTP status classification shared by all service/response errors: retry 5xx
+// and "Too Many Requests" (throttling / `SlowDown`), treat other 4xx (e.g.
+// `NoSuchKey` → 404) as terminal.
+fn status_is_retryable(status: u16) -> bool {
+    status >= 500 || status == 429
+}
+
+// Capped exponential backoff (pre-jitter) for a given attempt: `base * 2^attempt`
+// clamped to `max`. Saturating math keeps it well-defined for large attempts.
+fn backoff_ceiling(base_delay_ms: u64, max_delay_ms: u64, attempt: u32) -> u64 {
+    base_delay_ms
+        .saturating_mul(1u64 << attempt.min(63))
+        .min(max_delay_ms)
+}
+
+// Classifies a failed conditional `put_object` from its structured error. S3
+// signals a failed write precondition with HTTP 412; we read the status off the
+// typed `ServiceError` rather than substring-matching a stringified error.
+fn put_error_from_sdk(
+    err: SdkError<aws_sdk_s3::operation::put_object::PutObjectError>,
+    precondition: &PutPrecondition,
+) -> StoragePutError {
+    if let SdkError::ServiceError(service_err) = &err {
+        if service_err.raw().status().as_u16() == 412 {
+            return precondition_to_error(precondition);
+        }
+    }
+    StoragePutError::Other(err.to_string())
+}
+
+// Maps a failed (412) precondition to the caller-facing error: an absence guard
+// (`If-None-Match: *`) failing means the object already exists; an ETag guard
+// failing is a generic precondition failure.
+fn precondition_to_error(precondition: &PutPrecondition) -> StoragePutError {
+    match precondition {
+        PutPrecondition::IfNotExists => StoragePutError::AlreadyExists,
+        _ => StoragePutError::PreconditionFailed,
+    }
+}
+
+// A single part of a multipart upload.
+struct Part {
+    // 1-indexed part number, as required by the S3 API.
+    part_number: i32,
+    offset: usize,
+    length: usize,
+}
+
+// Splits an object of `file_size` bytes into fixed-size parts, the last of
+// which carries the remainder.
+fn part_plan(file_size: usize, part_size: usize) -> Vec<Part> {
+    let part_count = file_size.div_ceil(part_size);
+    (0..part_count)
+        .map(|part_index| {
+            let offset = part_index * part_size;
+            Part {
+                part_number: part_index as i32 + 1,
+                offset,
+                length: std::cmp::min(part_size, file_size - offset),
+            }
+        })
+        .collect()
+}
+
+// Renders a half-open `[start, end)` range as the inclusive HTTP
+// `bytes=start-{end-1}` header value, rejecting empty/inverted ranges.
+fn range_header(range: &Range<u64>) -> Result<String, String> {
+    if range.start >= range.end {
+        return Err(format!(
+            "invalid range: start {} must be less than end {}",
+            range.start, range.end
+        ));
+    }
+    Ok(format!("bytes={}-{}", range.start, range.end - 1))
+}
+
+fn static_credentials(key: &str, secret: &str) -> aws_sdk_s3::config::Credentials {
+    aws_sdk_s3::config::Credentials::new(
+        key.to_string(),
+        secret.to_string(),
+        None,
+        None,
+        "chroma-static",
+    )
+}
+
+// Write precondition applied to a conditional put.
+#[derive(Clone)]
+pub(crate) enum PutPrecondition {
+    // Unconditional write (overwrites any existing object).
+    None,
+    // Write only if the object does not already exist (`If-None-Match: *`).
+    IfNotExists,
+    // Write only if the current object's ETag matches.
+    IfMatch(String),
+}
+
+// Metadata about a stored object, returned by `head`.
+pub(crate) struct ObjectMeta {
+    pub(crate) size: i64,
+    pub(crate) etag: Option<String>,
+    pub(crate) last_modified: Option<String>,
+}
+
+#[derive(Error, Debug)]
+pub enum StoragePutError {
+    #[error("Object already exists")]
+    AlreadyExists,
+    #[error("Write precondition failed")]
+    PreconditionFailed,
+    #[error("Storage error: {0}")]
+    Other(String),
+}
+
+impl ChromaError for StoragePutError {
+    fn code(&self) -> crate::errors::ErrorCodes {
+        match self {
+            StoragePutError::AlreadyExists => crate::errors::ErrorCodes::AlreadyExists,
+            StoragePutError::PreconditionFailed => crate::errors::ErrorCodes::FailedPrecondition,
+            StoragePutError::Other(_) => crate::errors::ErrorCodes::Internal,
         }
     }
 }
@@ -154,10 +600,80 @@ impl Configurable<StorageConfig> for S3Storage {
     async fn try_from_config(config: &StorageConfig) -> Result<Self, Box<dyn ChromaError>> {
         match &config {
             StorageConfig::S3(s3_config) => {
-                let config = aws_config::load_from_env().await;
-                let client = aws_sdk_s3::Client::new(&config);
+                // Start from the environment-resolved config so that things like
+                // the default region provider chain still work when the operator
+                // hasn't pinned everything explicitly.
+                let env_config = aws_config::load_from_env().await;
+                let mut builder = aws_sdk_s3::config::Builder::from(&env_config);
+
+                if let Some(endpoint_url) = &s3_config.endpoint_url {
+                    builder = builder.endpoint_url(endpoint_url.clone());
+                }
+                // Region, pinned from config if present otherwise inherited from
+                // the environment. Reused below to configure the credential
+                // providers that need it.
+                let region = s3_config
+                    .region
+                    .clone()
+                    .map(aws_sdk_s3::config::Region::new)
+                    .or_else(|| env_config.region().cloned());
+                if let Some(region) = &region {
+                    builder = builder.region(region.clone());
+                }
+                // The explicit `credentials` selector takes precedence; the loose
+                // `aws_access_key_id`/`aws_secret_access_key` pair is still honored
+                // as a shorthand for `Static` when the selector is left at `Env`.
+                match &s3_config.credentials {
+                    S3CredentialsConfig::Static {
+                        aws_access_key_id,
+                        aws_secret_access_key,
+                    } => {
+                        builder = builder.credentials_provider(static_credentials(
+                            aws_access_key_id,
+                            aws_secret_access_key,
+                        ));
+                    }
+                    S3CredentialsConfig::WebIdentityToken => {
+                        // IRSA: exchange `AWS_WEB_IDENTITY_TOKEN_FILE`/`AWS_ROLE_ARN`
+                        // via STS. The provider needs a `ProviderConfig` carrying
+                        // the region (and default connector); build it explicitly
+                        // rather than converting from `SdkConfig`.
+                        let provider_config =
+                            aws_config::provider_config::ProviderConfig::without_region()
+                                .with_region(region.clone());
+                        let provider =
+                            aws_config::web_identity_token::WebIdentityTokenCredentialsProvider::builder()
+                                .configure(&provider_config)
+                                .build();
+                        builder = builder.credentials_provider(provider);
+                    }
+                    S3CredentialsConfig::InstanceMetadata => {
+                        let provider =
+                            aws_config::imds::credentials::ImdsCredentialsProvider::builder()
+                                .build();
+                        builder = builder.credentials_provider(provider);
+                    }
+                    S3CredentialsConfig::Env => {
+                        if let (Some(key), Some(secret)) = (
+                            &s3_config.aws_access_key_id,
+                            &s3_config.aws_secret_access_key,
+                        ) {
+                            builder =
+                                builder.credentials_provider(static_credentials(key, secret));
+                        }
+                    }
+                }
+                builder = builder.force_path_style(s3_config.force_path_style);
 
-                let storage = S3Storage::new(&s3_config.bucket, client);
+                let client = aws_sdk_s3::Client::from_conf(builder.build());
+                let storage = S3Storage::new(
+                    &s3_config.bucket,
+                    client,
+                    s3_config.multipart_threshold_bytes,
+                    s3_config.upload_part_size_bytes,
+                    s3_config.max_concurrent_upload_parts,
+                    s3_config.retry.clone(),
+                );
                 return Ok(storage);
             }
             _ => {
@@ -173,6 +689,77 @@ mod tests {
     use tempfile::tempdir;
     use tokio::io::AsyncReadExt;
 
+    #[test]
+    fn test_range_header() {
+        assert_eq!(range_header(&(0..1)).unwrap(), "bytes=0-0");
+        assert_eq!(range_header(&(0..1024)).unwrap(), "bytes=0-1023");
+        assert_eq!(range_header(&(4096..8192)).unwrap(), "bytes=4096-8191");
+        // Empty and inverted ranges are rejected.
+        assert!(range_header(&(10..10)).is_err());
+        assert!(range_header(&(20..10)).is_err());
+    }
+
+    #[test]
+    fn test_status_is_retryable() {
+        // Transient: 5xx and throttling.
+        assert!(status_is_retryable(500));
+        assert!(status_is_retryable(503));
+        assert!(status_is_retryable(429));
+        // Terminal: 4xx (NoSuchKey -> 404, precondition -> 412) and success.
+        assert!(!status_is_retryable(404));
+        assert!(!status_is_retryable(412));
+        assert!(!status_is_retryable(200));
+    }
+
+    #[test]
+    fn test_backoff_ceiling() {
+        // base * 2^attempt, doubling each attempt.
+        assert_eq!(backoff_ceiling(50, 5_000, 0), 50);
+        assert_eq!(backoff_ceiling(50, 5_000, 1), 100);
+        assert_eq!(backoff_ceiling(50, 5_000, 3), 400);
+        // Capped at max_delay_ms and never overflows for large attempts.
+        assert_eq!(backoff_ceiling(50, 5_000, 30), 5_000);
+        assert_eq!(backoff_ceiling(50, 5_000, 1000), 5_000);
+    }
+
+    #[test]
+    fn test_part_plan() {
+        // Exact multiple of the part size: two full parts.
+        let plan = part_plan(16, 8);
+        assert_eq!(plan.len(), 2);
+        assert_eq!((plan[0].part_number, plan[0].offset, plan[0].length), (1, 0, 8));
+        assert_eq!((plan[1].part_number, plan[1].offset, plan[1].length), (2, 8, 8));
+
+        // Remainder in the final part.
+        let plan = part_plan(20, 8);
+        assert_eq!(plan.len(), 3);
+        assert_eq!(plan[2].offset, 16);
+        assert_eq!(plan[2].length, 4);
+
+        // Smaller than one part.
+        let plan = part_plan(5, 8);
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].length, 5);
+
+        // Empty object yields no parts.
+        assert!(part_plan(0, 8).is_empty());
+    }
+
+    #[test]
+    fn test_precondition_to_error() {
+        // An absence guard (If-None-Match: *) failing means a concurrent writer
+        // already created the object.
+        assert!(matches!(
+            precondition_to_error(&PutPrecondition::IfNotExists),
+            StoragePutError::AlreadyExists
+        ));
+        // An ETag guard failing is a generic precondition failure.
+        assert!(matches!(
+            precondition_to_error(&PutPrecondition::IfMatch("etag".to_string())),
+            StoragePutError::PreconditionFailed
+        ));
+    }
+
     #[tokio::test]
     #[cfg(CHROMA_KUBERNETES_INTEGRATION)]
     async fn test_get() {
@@ -198,6 +785,10 @@ mod tests {
         let storage = S3Storage {
             bucket: "test".to_string(),
             client,
+            multipart_threshold_bytes: 32 * 1024 * 1024,
+            upload_part_size_bytes: 8 * 1024 * 1024,
+            max_concurrent_upload_parts: 8,
+            retry: StorageRetryConfig::default(),
         };
         storage.create_bucket().await.unwrap();
 